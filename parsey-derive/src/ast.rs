@@ -1,27 +1,27 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{Attribute, Data, DataEnum, DeriveInput, Ident, Meta};
+use syn::{
+    punctuated::Punctuated, Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Meta, Token,
+};
 
 pub fn ast_impl(input: TokenStream) -> TokenStream {
-    let input: DeriveInput = syn::parse2(input).expect("Token can only be derived for enums");
+    let input: DeriveInput = syn::parse2(input).expect("#[derive(Ast)] can only be used on enums");
 
     let name = &input.ident;
 
-    // Extract the #[ast] attribute
+    // Extract the #[ast(Token, Error)] attribute
     let ast_attr = input
         .attrs
         .iter()
         .find(|attr| attr.path().is_ident("ast"))
-        .expect("missing #[ast] attribute");
-    let (token_type, errror_type) = parse_ast_attribute(ast_attr); // Parse the attribute into metadata
+        .expect("missing #[ast(Token, Error)] attribute");
+    let (token_type, error_type) = parse_ast_attribute(ast_attr); // Parse the attribute into metadata
 
     // Generate the Ast implementation based on the input
-    let expanded = match &input.data {
-        Data::Enum(data_enum) => implement_ast_for_enum(name, data_enum, &token_type, &errror_type),
+    match &input.data {
+        Data::Enum(data_enum) => implement_ast_for_enum(name, data_enum, &token_type, &error_type),
         _ => panic!("#[derive(Ast)] is only supported for enums."),
-    };
-
-    TokenStream::from(expanded)
+    }
 }
 
 fn parse_ast_attribute(attr: &Attribute) -> (Ident, Ident) {
@@ -37,8 +37,7 @@ fn parse_ast_attribute(attr: &Attribute) -> (Ident, Ident) {
                 panic!("incorrect #[ast] format: expected Token type")
             }
 
-            if let Some(_) = tokens.next() {
-            } else {
+            if tokens.next().is_none() {
                 panic!("incorrect #[ast] format: expected Error type")
             }
 
@@ -48,24 +47,71 @@ fn parse_ast_attribute(attr: &Attribute) -> (Ident, Ident) {
                 panic!("incorrect #[ast] format: expected Error type")
             }
 
-            if let Some(_) = tokens.next() {
+            if tokens.next().is_some() {
                 panic!("incorrect #[ast] format: should only be two types: Token and Error")
             }
 
-            return (token_type, error_type);
+            (token_type, error_type)
         }
         _ => panic!("incorrect #[ast] format"),
     }
 }
 
-fn parse_ast_attributes(attr: &Attribute) -> Vec<Ident> {
-    match &attr.meta {
-        Meta::List(_list) => {
-            let idents = vec![];
-            idents
-        }
-        _ => panic!("incorrect #[ast] format"),
+/// One step of a variant's matching sequence, in the order they must appear in the input.
+enum Matcher {
+    /// A bare token ident, e.g. `OpenParen` in `#[ast(OpenParen, Inner(Expr), CloseParen)]`.
+    /// Matched and consumed with `expect`; contributes no field to the variant.
+    Token(Ident),
+    /// A nested `Ast` node, e.g. `Inner(Expr)`. Parsed with `Expr::parse` and stored as the
+    /// variant's next tuple field.
+    Node(syn::Path),
+    /// Zero or more repetitions of a nested `Ast` node, e.g. `Items(many(Term))`. Parsed with
+    /// `TokenStream::many` and stored as a `Vec<Term>` tuple field.
+    Many(syn::Path),
+}
+
+/// Parse a variant's `#[ast(...)]` attribute into its ordered sequence of matchers.
+fn parse_variant_matchers(attr: &Attribute) -> Vec<Matcher> {
+    let list = match &attr.meta {
+        Meta::List(list) => list,
+        _ => panic!("incorrect #[ast] format: expected a parenthesised list of matchers"),
+    };
+
+    let metas = list
+        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .expect("incorrect #[ast] format");
+
+    if metas.is_empty() {
+        panic!("#[ast] attribute must not be empty");
     }
+
+    metas
+        .into_iter()
+        .map(|meta| match meta {
+            // A bare ident, e.g. `OpenParen`: a literal token to expect and consume.
+            Meta::Path(path) => Matcher::Token(
+                path.get_ident()
+                    .expect("expected a bare token ident")
+                    .clone(),
+            ),
+            // A call-like form, e.g. `Inner(Expr)` or `Items(many(Term))`.
+            Meta::List(capture) => {
+                let inner: Meta = syn::parse2(capture.tokens.clone())
+                    .expect("incorrect #[ast] format: expected a type or `many(Type)`");
+
+                match inner {
+                    Meta::Path(node_type) => Matcher::Node(node_type),
+                    Meta::List(many) if many.path.is_ident("many") => {
+                        let repeated: syn::Path = syn::parse2(many.tokens.clone())
+                            .expect("incorrect #[ast] format: `many(...)` expects a single type");
+                        Matcher::Many(repeated)
+                    }
+                    _ => panic!("incorrect #[ast] format: expected a type or `many(Type)`"),
+                }
+            }
+            _ => panic!("incorrect #[ast] format"),
+        })
+        .collect()
 }
 
 fn implement_ast_for_enum(
@@ -74,43 +120,86 @@ fn implement_ast_for_enum(
     token_type: &Ident,
     error_type: &Ident,
 ) -> TokenStream {
-    let match_arms = data_enum.variants.iter().map(|variant| {
+    let variant_attempts = data_enum.variants.iter().map(|variant| {
         let variant_name = &variant.ident;
+
+        if !matches!(variant.fields, Fields::Unit | Fields::Unnamed(_)) {
+            panic!(
+                "variant {} must be a unit or tuple variant",
+                variant_name
+            );
+        }
+
         let ast_attr = variant
             .attrs
             .iter()
             .find(|attr| attr.path().is_ident("ast"))
-            .expect(&format!(
-                "variant {} missing #[ast] attribute",
-                variant_name
-            ));
-
-        let token_patterns = parse_ast_attributes(ast_attr);
+            .unwrap_or_else(|| panic!("variant {} missing #[ast] attribute", variant_name));
+
+        let matchers = parse_variant_matchers(ast_attr);
+
+        let mut steps = vec![];
+        let mut captured_fields = vec![];
+
+        for matcher in &matchers {
+            match matcher {
+                Matcher::Token(token) => steps.push(quote! {
+                    __token_stream.expect(#token_type::#token)?;
+                }),
+                Matcher::Node(node_type) => {
+                    let field = quote::format_ident!("__field_{}", captured_fields.len());
+                    steps.push(quote! {
+                        let #field = <#node_type as parsey::Ast<#token_type, #error_type>>::parse(__token_stream)?;
+                    });
+                    captured_fields.push(field);
+                }
+                Matcher::Many(node_type) => {
+                    let field = quote::format_ident!("__field_{}", captured_fields.len());
+                    steps.push(quote! {
+                        let #field: ::std::vec::Vec<#node_type> = __token_stream.many()?;
+                    });
+                    captured_fields.push(field);
+                }
+            }
+        }
 
-        if token_patterns.is_empty() {
-            panic!("#[ast] attribute must not be empty");
+        let construct = if captured_fields.is_empty() {
+            quote! { #name::#variant_name }
         } else {
-            // Generate token match patterns for each token in the list
-            let pattern = quote! {
-                (#(#token_patterns),*)
-            };
-            quote! {
-                #pattern => Ok(#name::#variant_name),
+            quote! { #name::#variant_name(#(#captured_fields),*) }
+        };
+
+        quote! {
+            let __checkpoint = __token_stream.checkpoint();
+            let __attempt: ::core::result::Result<Self, #error_type> = (|| {
+                #(#steps)*
+                ::core::result::Result::Ok(#construct)
+            })();
+            match __attempt {
+                ::core::result::Result::Ok(value) => {
+                    __token_stream.commit(__checkpoint);
+                    return ::core::result::Result::Ok(value);
+                }
+                ::core::result::Result::Err(_) => {
+                    __token_stream.rollback(__checkpoint);
+                }
             }
         }
     });
 
-    // Generate the Ast implementation
+    let name_str = name.to_string();
+
     quote! {
-        impl<#token_type, #error_type> Ast<#token_type, #error_type> for #name {
-            fn parse<P>(parser: &mut std::iter::Peekable<P>) -> Result<Self, #error_type>
+        impl parsey::Ast<#token_type, #error_type> for #name {
+            fn parse<P>(
+                __token_stream: &mut parsey::TokenStream<P, #token_type, #error_type>,
+            ) -> ::core::result::Result<Self, #error_type>
             where
-                P: Parser<#token_type, #error_type>,
+                P: parsey::Parser<#token_type, #error_type, Context = ()>,
             {
-                match parser.next() {
-                    #(#match_arms)*
-                    _ => Err(#error_type),
-                }
+                #(#variant_attempts)*
+
+                ::core::result::Result::Err(__token_stream.expected_err(#name_str))
             }
         }
     }
@@ -8,7 +8,10 @@ use crate::{parser::Parser, token_stream::TokenStream};
 /// # Type Parameters
 /// - `Token`: The type of tokens being parsed.
 /// - `Error`: The type of errors that may occur during parsing.
-pub trait Ast<Token, Error>: Sized {
+/// - `Context`: Ambient parsing state threaded through via [`TokenStream`]. Defaults to `()`;
+///   a node that needs to read `token_stream.context()` implements `Ast<Token, Error, Context>`
+///   for its specific `Context` type instead.
+pub trait Ast<Token, Error, Context = ()>: Sized {
     /// Parses an AST node from a peekable token stream.
     ///
     /// # Parameters
@@ -21,5 +24,5 @@ pub trait Ast<Token, Error>: Sized {
     /// Returns an error of type `Error` if the token sequence does not match the expected structure.
     fn parse<P>(token_stream: &mut TokenStream<P, Token, Error>) -> Result<Self, Error>
     where
-        P: Parser<Token, Error>;
+        P: Parser<Token, Error, Context = Context>;
 }
@@ -0,0 +1,174 @@
+use crate::{ast::Ast, error::ParseError, parser::Parser, token_stream::TokenStream};
+
+/// A single alternative passed to [`TokenStream::alt`]: a parse function tried in order against
+/// the stream.
+pub type Alternative<P, Token, Error, A> = fn(&mut TokenStream<P, Token, Error>) -> Result<A, Error>;
+
+/// Combinator methods for building grammars out of [`TokenStream`] without hand-writing a
+/// `match` arm for every token.
+impl<P, Token, Error> TokenStream<P, Token, Error>
+where
+    P: Parser<Token, Error>,
+    Token: Clone,
+{
+    /// Consume and return the next token if it is equal to one of the tokens in `set`.
+    pub fn one_of(&mut self, set: &[Token]) -> Result<Token, Error>
+    where
+        Token: PartialEq,
+        Error: ParseError<Token>,
+    {
+        match self.peek() {
+            Some(token) if set.contains(token) => Ok(self.next().unwrap()),
+            _ => Err(self.expected_err("one of a set of tokens")),
+        }
+    }
+
+    /// Consume and return the next token if it is not equal to any of the tokens in `set`.
+    pub fn none_of(&mut self, set: &[Token]) -> Result<Token, Error>
+    where
+        Token: PartialEq,
+        Error: ParseError<Token>,
+    {
+        match self.peek() {
+            Some(token) if !set.contains(token) => Ok(self.next().unwrap()),
+            _ => Err(self.expected_err("none of a set of tokens")),
+        }
+    }
+
+    /// Consume and return tokens for as long as `predicate` holds, stopping at the first token
+    /// that does not match (or at the end of the stream).
+    ///
+    /// Named `take_tokens_while` rather than `take_while`: the latter would be shadowed by the
+    /// by-value trait method `Iterator::take_while`, since `TokenStream: Iterator`, and calls would silently
+    /// resolve to the wrong method instead of failing to compile.
+    pub fn take_tokens_while<F>(&mut self, predicate: F) -> Vec<Token>
+    where
+        F: Fn(&Token) -> bool,
+    {
+        let mut tokens = vec![];
+        while let Some(token) = self.peek() {
+            if predicate(token) {
+                tokens.push(self.next().unwrap());
+            } else {
+                break;
+            }
+        }
+        tokens
+    }
+
+    /// Parse `A` repeatedly until it stops matching, returning everything parsed so far.
+    ///
+    /// Each attempt goes through [`try_parse`](Self::try_parse), so a failing final element
+    /// rolls the stream back to right before it was tried instead of leaving the stream
+    /// partway through a failed parse.
+    ///
+    /// Assumes `A::parse` always consumes at least one token on success; if it doesn't, this
+    /// stops after the first zero-progress item rather than looping forever.
+    pub fn many<A>(&mut self) -> Result<Vec<A>, Error>
+    where
+        A: Ast<Token, Error, P::Context>,
+    {
+        let mut items = vec![];
+        loop {
+            let position = self.position();
+            match self.try_parse() {
+                Ok(item) => {
+                    items.push(item);
+                    if self.position() == position {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+        Ok(items)
+    }
+
+    /// Parse a list of `A` values separated by `sep`, e.g. a comma-separated list.
+    ///
+    /// Assumes `A::parse` always consumes at least one token on success; inside the loop it is
+    /// only called right after `sep` is consumed, so a zero-progress `A` cannot itself cause an infinite
+    /// loop here, but it would produce a list entry that didn't advance the stream.
+    pub fn separated<A>(&mut self, sep: Token) -> Result<Vec<A>, Error>
+    where
+        A: Ast<Token, Error, P::Context>,
+        Token: PartialEq,
+    {
+        let mut items = vec![];
+
+        match self.try_parse() {
+            Ok(item) => items.push(item),
+            Err(_) => return Ok(items),
+        }
+
+        while self.peek() == Some(&sep) {
+            self.next();
+            items.push(A::parse(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Parse `inner`, requiring it to be surrounded by `open` and `close` tokens.
+    pub fn delimited<T>(
+        &mut self,
+        open: Token,
+        inner: impl FnOnce(&mut Self) -> Result<T, Error>,
+        close: Token,
+    ) -> Result<T, Error> {
+        self.expect(open)?;
+        let value = inner(self)?;
+        self.expect(close)?;
+        Ok(value)
+    }
+
+    /// Attempt to parse an `A`, rolling the stream back to where it started if that fails.
+    ///
+    /// This is what makes ordered-choice grammars safe: a failed alternative never leaves the
+    /// stream partway through the tokens it tried to consume.
+    pub fn try_parse<A>(&mut self) -> Result<A, Error>
+    where
+        A: Ast<Token, Error, P::Context>,
+    {
+        let checkpoint = self.checkpoint();
+        match A::parse(self) {
+            Ok(value) => {
+                self.commit(checkpoint);
+                Ok(value)
+            }
+            Err(err) => {
+                self.rollback(checkpoint);
+                Err(err)
+            }
+        }
+    }
+
+    /// Try each parse function in order, returning the first result that parses successfully.
+    ///
+    /// Each alternative runs under its own checkpoint and is rolled back if it fails, so a
+    /// failed attempt never consumes tokens from the next one. Returns the last alternative's
+    /// error if none of them match.
+    pub fn alt<A>(
+        &mut self,
+        alternatives: &[Alternative<P, Token, Error, A>],
+    ) -> Result<A, Error>
+    where
+        Error: ParseError<Token>,
+    {
+        let mut last_err = self.expected_err("one of several alternatives");
+        for alternative in alternatives {
+            let checkpoint = self.checkpoint();
+            match alternative(self) {
+                Ok(value) => {
+                    self.commit(checkpoint);
+                    return Ok(value);
+                }
+                Err(err) => {
+                    self.rollback(checkpoint);
+                    last_err = err;
+                }
+            }
+        }
+        Err(last_err)
+    }
+}
@@ -0,0 +1,19 @@
+/// A trait implemented by error types so that [`TokenStream`](crate::TokenStream) can build
+/// descriptive, position-aware errors on the caller's behalf.
+///
+/// `parsey` keeps `Error` fully generic, so it cannot know how a concrete error type wants to
+/// render a message. Instead, implementing `ParseError` tells `parsey` how to construct one from
+/// the pieces it does know: where parsing stopped, what was expected, and (if anything) what was
+/// actually found.
+///
+/// # Type Parameters
+/// - `Token`: The type of tokens being parsed.
+pub trait ParseError<Token> {
+    /// Build an error reporting that `what` was expected at token index `pos`, but `found` was
+    /// encountered instead (or `None` if the stream had already ended).
+    fn expected(pos: usize, what: &str, found: Option<&Token>) -> Self;
+
+    /// Build an error reporting that `what` was expected at token index `pos`, but the token
+    /// stream ended first.
+    fn unexpected_eof(pos: usize, what: &str) -> Self;
+}
@@ -20,7 +20,7 @@
 //! ```rust,ignore
 //! use parsey::{parse, require_next_n, Ast, Parser, TokenStream};
 //!
-//! #[derive(Debug, PartialEq)]
+//! #[derive(Debug, Clone, PartialEq)]
 //! pub enum MyToken {
 //!     Zero,
 //!     One,
@@ -34,6 +34,7 @@
 //! }
 //!
 //! impl Parser<MyToken, MyError> for MyParser {
+//!     type Context = ();
 //!     type Root = Root;
 //!
 //!     fn expect(
@@ -84,7 +85,7 @@
 //! impl Ast<MyToken, MyError> for Root {
 //!     fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
 //!     where
-//!         P: Parser<MyToken, MyError>,
+//!         P: Parser<MyToken, MyError, Context = ()>,
 //!     {
 //!         let mut two_bits = vec![];
 //!         while !token_stream.is_empty() {
@@ -97,7 +98,7 @@
 //! impl Ast<MyToken, MyError> for TwoBit {
 //!     fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
 //!     where
-//!         P: parsey::Parser<MyToken, MyError>,
+//!         P: parsey::Parser<MyToken, MyError, Context = ()>,
 //!     {
 //!         use MyToken::*;
 //!         use TwoBit::*;
@@ -126,11 +127,17 @@
 //! ```
 
 pub use ast::Ast;
+pub use error::ParseError;
 pub use parser::Parser;
-pub use token_stream::TokenStream;
+pub use parsey_derive::Ast;
+pub use pratt::{InfixOp, PrattAst};
+pub use token_stream::{Checkpoint, TokenStream};
 
 mod ast;
+mod combinators;
+mod error;
 mod parser;
+mod pratt;
 mod token_stream;
 
 /// Parse a vec of tokens into the provided root AST node.
@@ -1,43 +1,38 @@
-use parsey::{Ast, Parser, PeekableParser};
+use parsey::{require_next_n, Ast, Parser, TokenStream};
 
 pub fn main() {
-    let tokens = vec![Token::One, Token::Zero, Token::One, Token::Zero];
-    let parser = MyParser::new(tokens);
-    match parser.parse() {
+    use Token::{One, Zero};
+
+    let tokens = vec![Zero, Zero, Zero, One, One, Zero, One, One];
+    match parsey::parse::<MyParser, Token, Error>(tokens) {
         Ok(ast) => println!("Ast: {:?}", ast),
         Err(e) => eprintln!("Parsing error: {:?}", e),
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     Zero,
     One,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Error;
 
 pub struct MyParser {
     tokens: Vec<Token>,
 }
 
-impl MyParser {
-    pub fn new(mut tokens: Vec<Token>) -> Self {
-        tokens.reverse();
-        Self { tokens }
-    }
-}
-
 impl Parser<Token, Error> for MyParser {
+    type Context = ();
     type Root = Root;
 
     fn expect(
-        peekable_parser: &mut PeekableParser<Self, Token, Error>,
+        token_stream: &mut TokenStream<Self, Token, Error>,
         token: Token,
     ) -> Result<(), Error> {
-        if peekable_parser.peek() == Some(&token) {
-            peekable_parser.next();
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
             Ok(())
         } else {
             Err(Error)
@@ -53,23 +48,30 @@ impl Iterator for MyParser {
     }
 }
 
-#[derive(Debug)]
+impl From<Vec<Token>> for MyParser {
+    fn from(mut value: Vec<Token>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub struct Root(Vec<Statement>);
 
 impl Ast<Token, Error> for Root {
-    fn parse<P>(parser: &mut PeekableParser<P, Token, Error>) -> Result<Self, Error>
+    fn parse<P>(token_stream: &mut TokenStream<P, Token, Error>) -> Result<Self, Error>
     where
-        P: Parser<Token, Error>,
+        P: Parser<Token, Error, Context = ()>,
     {
         let mut statements = vec![];
-        while parser.peek().is_some() {
-            statements.push(Statement::parse(parser)?);
+        while !token_stream.is_empty() {
+            statements.push(Statement::parse(token_stream)?);
         }
         Ok(Self(statements))
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Statement {
     ZeroZero,
     ZeroOne,
@@ -78,10 +80,18 @@ pub enum Statement {
 }
 
 impl Ast<Token, Error> for Statement {
-    fn parse<P>(_parser: &mut PeekableParser<P, Token, Error>) -> Result<Self, Error>
+    fn parse<P>(token_stream: &mut TokenStream<P, Token, Error>) -> Result<Self, Error>
     where
-        P: Parser<Token, Error>,
+        P: Parser<Token, Error, Context = ()>,
     {
-        todo!()
+        use Statement::*;
+        use Token::*;
+
+        match require_next_n!(token_stream, 2, Error) {
+            [Zero, Zero] => Ok(ZeroZero),
+            [Zero, One] => Ok(ZeroOne),
+            [One, Zero] => Ok(OneZero),
+            [One, One] => Ok(OneOne),
+        }
     }
 }
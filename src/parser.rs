@@ -1,5 +1,3 @@
-use core::marker::PhantomData;
-
 use crate::{ast::Ast, token_stream::TokenStream};
 
 /// A trait representing a generic parser that consumes tokens and produces an AST.
@@ -10,8 +8,13 @@ use crate::{ast::Ast, token_stream::TokenStream};
 /// - `Token`: The type of tokens being parsed.
 /// - `Error`: The type of errors that may occur during parsing.
 pub trait Parser<Token, Error>: Iterator<Item = Token> + From<Vec<Token>> + Sized {
+    /// Ambient parsing state (e.g. "no struct literals here") threaded through nested parses
+    /// via [`TokenStream::with_context`]. Grammars that don't need any restrictions should set
+    /// this to `()`.
+    type Context: Default;
+
     /// The root type of the AST produced by this parser.
-    type Root: Ast<Token, Error>;
+    type Root: Ast<Token, Error, Self::Context>;
 
     /// Parses an AST from a peekable token stream.
     ///
@@ -20,12 +23,11 @@ pub trait Parser<Token, Error>: Iterator<Item = Token> + From<Vec<Token>> + Size
     ///
     /// # Errors
     /// Returns an error of type `Error` if the token sequence does not match the expected structure.
-    fn parse(self) -> Result<Self::Root, Error> {
-        Self::Root::parse(&mut TokenStream {
-            inner: self.peekable(),
-            token_phantom: PhantomData,
-            error_phantom: PhantomData,
-        })
+    fn parse(self) -> Result<Self::Root, Error>
+    where
+        Self::Context: Default,
+    {
+        Self::Root::parse(&mut TokenStream::from(self))
     }
 
     /// Validates whether a given token matches the expected token.
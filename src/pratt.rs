@@ -0,0 +1,57 @@
+use crate::{parser::Parser, token_stream::TokenStream};
+
+/// A token that can act as a binary operator in a Pratt (operator-precedence) grammar.
+///
+/// `binding_power` returns the left and right binding powers of `tok`, or `None` if `tok` is
+/// not an operator at all. Left-associative operators should return `(bp, bp + 1)` and
+/// right-associative operators `(bp + 1, bp)`, so that recursing with the right binding power
+/// naturally stops at operators of equal precedence for left-associative ones, and recurses
+/// through them for right-associative ones.
+pub trait InfixOp<Token> {
+    /// Returns the `(left_binding_power, right_binding_power)` of `tok`, or `None` if `tok`
+    /// is not a binary operator.
+    fn binding_power(tok: &Token) -> Option<(u8, u8)>;
+}
+
+/// An AST node that can be built up from a Pratt expression grammar, via
+/// [`TokenStream::parse_expr`].
+pub trait PrattAst<Token, Error, Context = ()>: Sized {
+    /// Parse a single atom (or prefix expression) — the left-hand side `parse_expr` starts
+    /// from before it looks for an infix operator.
+    fn parse_primary<P>(token_stream: &mut TokenStream<P, Token, Error>) -> Result<Self, Error>
+    where
+        P: Parser<Token, Error, Context = Context>;
+
+    /// Combine `lhs` and `rhs` with the binary operator token `op`.
+    fn build_binop(op: Token, lhs: Self, rhs: Self) -> Self;
+}
+
+impl<P, Token, Error> TokenStream<P, Token, Error>
+where
+    P: Parser<Token, Error>,
+    Token: Clone + InfixOp<Token>,
+{
+    /// Parse a Pratt (operator-precedence) expression, only consuming infix operators whose
+    /// left binding power is at least `min_bp`.
+    ///
+    /// Call this with `min_bp: 0` to parse a full expression.
+    pub fn parse_expr<A>(&mut self, min_bp: u8) -> Result<A, Error>
+    where
+        A: PrattAst<Token, Error, P::Context>,
+    {
+        let mut lhs = A::parse_primary(self)?;
+
+        while let Some((l_bp, r_bp)) = self.peek().and_then(Token::binding_power) {
+            if l_bp < min_bp {
+                break;
+            }
+
+            // Unwrapping here is safe: `peek` just confirmed there is a next token.
+            let op = self.next().unwrap();
+            let rhs = self.parse_expr(r_bp)?;
+            lhs = A::build_binop(op, lhs, rhs);
+        }
+
+        Ok(lhs)
+    }
+}
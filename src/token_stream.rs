@@ -4,7 +4,7 @@ use core::{
 };
 use std::collections::VecDeque;
 
-use crate::parser::Parser;
+use crate::{error::ParseError, parser::Parser};
 
 /// A wrapper around a peekable parser that provides lookahead functionality.
 ///
@@ -13,7 +13,8 @@ use crate::parser::Parser;
 /// upcoming tokens.
 ///
 /// # Type Parameters
-/// - `P`: The underlying parser type that implements [`Parser`]
+/// - `P`: The underlying parser type that implements [`Parser`]. Its [`Parser::Context`]
+///   associated type is the type of [`context`](Self::context) stored here.
 /// - `Token`: The type of tokens being parsed
 /// - `Error`: The type of errors that may occur during parsing
 ///
@@ -35,12 +36,34 @@ where
 {
     parser: P,
     peeked: VecDeque<Option<Token>>,
+    /// The number of tokens consumed so far via [`next`](Self::next) / [`next_n`](Self::next_n).
+    /// Unaffected by peeking.
+    position: usize,
+    /// Tokens consumed while at least one [`Checkpoint`] was open, kept around so
+    /// [`rollback`](Self::rollback) can push them back onto `peeked`.
+    history: VecDeque<Token>,
+    /// The number of [`Checkpoint`]s currently outstanding. While this is non-zero, consumed
+    /// tokens are retained in `history` instead of being dropped.
+    open_checkpoints: usize,
+    /// The current parsing context, e.g. "statement position" or "no struct literals here".
+    context: P::Context,
     error_phantom: PhantomData<Error>,
 }
 
+/// A saved position in a [`TokenStream`], taken with [`TokenStream::checkpoint`] and restored
+/// with [`TokenStream::rollback`].
+///
+/// This allows speculatively trying a parse and backing out cleanly if it fails, similar to
+/// `syn`'s `Cursor::fork`.
+pub struct Checkpoint {
+    position: usize,
+    history_len: usize,
+}
+
 impl<P, Token, Error> TokenStream<P, Token, Error>
 where
     P: Parser<Token, Error>,
+    Token: Clone,
 {
     /// Validates whether a given token matches the expected token.
     ///
@@ -122,16 +145,112 @@ where
     pub fn consume(&mut self, n: usize) {
         self.next_n(n);
     }
+
+    /// The index of the next token to be consumed, i.e. the number of tokens consumed so far.
+    ///
+    /// This only advances via [`next`](Self::next) / [`next_n`](Self::next_n); peeking does not
+    /// affect it.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Record the current position in the stream, to be restored later with
+    /// [`rollback`](Self::rollback) if a speculative parse doesn't pan out.
+    ///
+    /// Checkpoints nest: rolling back to an outer checkpoint after an inner one was already
+    /// rolled back (or simply dropped) still works correctly.
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.open_checkpoints += 1;
+        Checkpoint {
+            position: self.position,
+            history_len: self.history.len(),
+        }
+    }
+
+    /// Restore the stream to the position recorded by `checkpoint`, as if the tokens consumed
+    /// since then had never been taken.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        while self.history.len() > checkpoint.history_len {
+            if let Some(token) = self.history.pop_back() {
+                self.peeked.push_front(Some(token));
+            }
+        }
+        self.position = checkpoint.position;
+        self.close_checkpoint();
+    }
+
+    /// Forget a checkpoint without rolling back, keeping whatever was consumed since it was
+    /// taken.
+    pub fn commit(&mut self, checkpoint: Checkpoint) {
+        self.close_checkpoint();
+        let _ = checkpoint;
+    }
+
+    fn close_checkpoint(&mut self) {
+        self.open_checkpoints = self.open_checkpoints.saturating_sub(1);
+        if self.open_checkpoints == 0 {
+            self.history.clear();
+        }
+    }
+
+    /// The current parsing context.
+    ///
+    /// Defaults to `Context::default()` (typically `()`) and is only ever changed for the
+    /// duration of a nested parse via [`with_context`](Self::with_context).
+    pub fn context(&self) -> &P::Context {
+        &self.context
+    }
+
+    /// Run `f` with the context temporarily set to `ctx`, restoring the previous context
+    /// afterwards — even if `f` returns early via `?`.
+    ///
+    /// This is how a grammar expresses something like "no struct literals here": a nested
+    /// parse sets the restriction for the duration of `f`, and whatever the context was before
+    /// is back in place as soon as `f` returns.
+    pub fn with_context<R>(&mut self, ctx: P::Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        let previous = core::mem::replace(&mut self.context, ctx);
+        let result = f(self);
+        self.context = previous;
+        result
+    }
+}
+
+impl<P, Token, Error> TokenStream<P, Token, Error>
+where
+    P: Parser<Token, Error>,
+    Token: Clone,
+    Error: ParseError<Token>,
+{
+    /// Build an error reporting that `what` was expected at the current position, describing
+    /// whichever token was actually found next (or that the stream ended).
+    pub fn expected_err(&mut self, what: &str) -> Error {
+        let pos = self.position;
+        match self.peek() {
+            Some(found) => Error::expected(pos, what, Some(found)),
+            None => Error::unexpected_eof(pos, what),
+        }
+    }
+
+    /// Build an error reporting that the token found at the current position was not expected,
+    /// without committing to a description of what would have been valid instead.
+    pub fn unexpected_err(&mut self) -> Error {
+        self.expected_err("a valid token")
+    }
 }
 
 impl<P, Token, Error> From<P> for TokenStream<P, Token, Error>
 where
     P: Parser<Token, Error>,
+    P::Context: Default,
 {
     fn from(value: P) -> Self {
         Self {
             parser: value,
             peeked: VecDeque::new(),
+            position: 0,
+            history: VecDeque::new(),
+            open_checkpoints: 0,
+            context: P::Context::default(),
             error_phantom: PhantomData,
         }
     }
@@ -160,14 +279,22 @@ where
 impl<P, Token, Error> Iterator for TokenStream<P, Token, Error>
 where
     P: Parser<Token, Error>,
+    Token: Clone,
 {
     type Item = Token;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.peeked.pop_front() {
+        let token = match self.peeked.pop_front() {
             Some(v) => v,
             None => self.parser.next(),
+        };
+        if let Some(token) = &token {
+            self.position += 1;
+            if self.open_checkpoints > 0 {
+                self.history.push_back(token.clone());
+            }
         }
+        token
     }
 }
@@ -0,0 +1,150 @@
+use parsey::{require_next_n, Ast, ParseError, Parser, TokenStream};
+
+#[test]
+pub fn try_parse_rolls_back_on_failure() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::Zero, MyToken::One]));
+
+    let result: Result<OneOne, MyError> = stream.try_parse();
+    assert!(result.is_err());
+
+    // The failed attempt consumed both tokens internally, but `try_parse` should have rolled
+    // the stream back to where it started.
+    assert_eq!(stream.position(), 0);
+    assert_eq!(stream.peek(), Some(&MyToken::Zero));
+}
+
+#[test]
+pub fn try_parse_commits_on_success() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::One, MyToken::One]));
+
+    let result: Result<OneOne, MyError> = stream.try_parse();
+    assert_eq!(result, Ok(OneOne));
+    assert_eq!(stream.position(), 2);
+    assert!(stream.is_empty());
+}
+
+#[test]
+pub fn alt_tries_each_alternative_in_order() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::One]));
+
+    let value = stream
+        .alt(&[parse_zero as fn(&mut _) -> _, parse_one as fn(&mut _) -> _])
+        .unwrap();
+    assert_eq!(value, ZeroOrOne::One);
+    assert!(stream.is_empty());
+}
+
+#[test]
+pub fn alt_returns_the_last_error_when_nothing_matches() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::Comma]));
+
+    let result = stream.alt(&[parse_zero as fn(&mut _) -> _, parse_one as fn(&mut _) -> _]);
+    assert!(result.is_err());
+    // No alternative should have consumed the stray token.
+    assert_eq!(stream.position(), 0);
+}
+
+fn parse_zero<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<ZeroOrOne, MyError>
+where
+    P: Parser<MyToken, MyError, Context = ()>,
+{
+    token_stream.expect(MyToken::Zero)?;
+    Ok(ZeroOrOne::Zero)
+}
+
+fn parse_one<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<ZeroOrOne, MyError>
+where
+    P: Parser<MyToken, MyError, Context = ()>,
+{
+    token_stream.expect(MyToken::One)?;
+    Ok(ZeroOrOne::One)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ZeroOrOne {
+    Zero,
+    One,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Zero,
+    One,
+    Comma,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+impl ParseError<MyToken> for MyError {
+    fn expected(_pos: usize, _what: &str, _found: Option<&MyToken>) -> Self {
+        MyError
+    }
+
+    fn unexpected_eof(_pos: usize, _what: &str) -> Self {
+        MyError
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
+    type Root = Root;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(MyError)
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Root;
+
+impl Ast<MyToken, MyError> for Root {
+    fn parse<P>(_token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OneOne;
+
+impl Ast<MyToken, MyError> for OneOne {
+    fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        match require_next_n!(token_stream, 2, MyError) {
+            [MyToken::One, MyToken::One] => Ok(OneOne),
+            _ => Err(MyError),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use parsey::{Ast, ParseError, Parser, TokenStream};
+
+#[test]
+pub fn one_of_and_none_of() {
+    let mut stream = TokenStream::from(MyParser::from(vec![
+        MyToken::One,
+        MyToken::Zero,
+        MyToken::Comma,
+    ]));
+
+    assert_eq!(
+        stream.one_of(&[MyToken::Zero, MyToken::One]),
+        Ok(MyToken::One)
+    );
+    assert_eq!(
+        stream.none_of(&[MyToken::Comma]),
+        Ok(MyToken::Zero)
+    );
+    assert!(stream.none_of(&[MyToken::Comma]).is_err());
+}
+
+#[test]
+pub fn take_while_collects_matching_tokens() {
+    let mut stream = TokenStream::from(MyParser::from(vec![
+        MyToken::One,
+        MyToken::One,
+        MyToken::Zero,
+        MyToken::Comma,
+    ]));
+
+    let ones = stream.take_tokens_while(|token| *token == MyToken::One);
+    assert_eq!(ones, vec![MyToken::One, MyToken::One]);
+    assert_eq!(stream.peek(), Some(&MyToken::Zero));
+}
+
+#[test]
+pub fn many_parses_until_it_stops_matching() {
+    use MyToken::{Comma, One, Zero};
+
+    let mut stream = TokenStream::from(MyParser::from(vec![Zero, One, Zero, Comma]));
+    let digits: Vec<Digit> = stream.many().unwrap();
+    assert_eq!(digits, vec![Digit::Zero, Digit::One, Digit::Zero]);
+}
+
+#[test]
+pub fn separated_parses_a_comma_list() {
+    use MyToken::{Comma, One, Zero};
+
+    let mut stream = TokenStream::from(MyParser::from(vec![Zero, Comma, One, Comma, Zero]));
+    let digits: Vec<Digit> = stream.separated(Comma).unwrap();
+    assert_eq!(digits, vec![Digit::Zero, Digit::One, Digit::Zero]);
+}
+
+#[test]
+pub fn delimited_requires_the_surrounding_tokens() {
+    use MyToken::{One, Zero};
+
+    let mut stream = TokenStream::from(MyParser::from(vec![Zero, One, Zero]));
+    let inner = stream
+        .delimited(Zero, Digit::parse, Zero)
+        .unwrap();
+    assert_eq!(inner, Digit::One);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Zero,
+    One,
+    Comma,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+impl ParseError<MyToken> for MyError {
+    fn expected(_pos: usize, _what: &str, _found: Option<&MyToken>) -> Self {
+        MyError
+    }
+
+    fn unexpected_eof(_pos: usize, _what: &str) -> Self {
+        MyError
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
+    type Root = Root;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(token_stream.expected_err("a specific token"))
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Root;
+
+impl Ast<MyToken, MyError> for Root {
+    fn parse<P>(_token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Digit {
+    Zero,
+    One,
+}
+
+impl Ast<MyToken, MyError> for Digit {
+    fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        match token_stream.one_of(&[MyToken::Zero, MyToken::One])? {
+            MyToken::Zero => Ok(Digit::Zero),
+            MyToken::One => Ok(Digit::One),
+            MyToken::Comma => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use parsey::{Ast, ParseError, Parser, TokenStream};
+
+/// Whether struct-literal braces are allowed at the current parsing position, mirroring
+/// rustc's `Restrictions::NO_STRUCT_LITERAL`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Restrictions {
+    no_struct_literal: bool,
+}
+
+#[test]
+pub fn with_context_scopes_the_restriction_and_restores_it() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::Brace]));
+    assert_eq!(*stream.context(), Restrictions::default());
+
+    stream.with_context(
+        Restrictions {
+            no_struct_literal: true,
+        },
+        |inner| {
+            assert!(inner.context().no_struct_literal);
+        },
+    );
+
+    // The restriction only applied for the duration of the closure.
+    assert!(!stream.context().no_struct_literal);
+}
+
+#[test]
+pub fn condition_rejects_struct_literals_when_restricted() {
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::Brace]));
+
+    let restricted = stream.with_context(
+        Restrictions {
+            no_struct_literal: true,
+        },
+        |inner| Condition::parse(inner),
+    );
+    assert_eq!(restricted, Err(MyError));
+
+    let mut stream = TokenStream::from(MyParser::from(vec![MyToken::Brace]));
+    assert_eq!(Condition::parse(&mut stream), Ok(Condition::StructLiteral));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Brace,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+impl ParseError<MyToken> for MyError {
+    fn expected(_pos: usize, _what: &str, _found: Option<&MyToken>) -> Self {
+        MyError
+    }
+
+    fn unexpected_eof(_pos: usize, _what: &str) -> Self {
+        MyError
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = Restrictions;
+    type Root = Condition;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(MyError)
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Condition {
+    StructLiteral,
+}
+
+impl Ast<MyToken, MyError, Restrictions> for Condition {
+    fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = Restrictions>,
+    {
+        if token_stream.context().no_struct_literal {
+            return Err(MyError);
+        }
+
+        token_stream.expect(MyToken::Brace)?;
+        Ok(Condition::StructLiteral)
+    }
+}
@@ -0,0 +1,106 @@
+use parsey::{Ast, ParseError, Parser, TokenStream};
+
+#[test]
+pub fn two_bit_via_derive() {
+    use MyToken::{One, Zero};
+    use TwoBit::{OneOne, OneZero, ZeroOne, ZeroZero};
+
+    let tokens = vec![Zero, Zero, Zero, One, One, Zero, One, One];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+
+    let mut two_bits = vec![];
+    while !stream.is_empty() {
+        two_bits.push(TwoBit::parse(&mut stream).unwrap());
+    }
+
+    assert_eq!(two_bits, vec![ZeroZero, ZeroOne, OneZero, OneOne]);
+}
+
+#[test]
+pub fn derived_ast_captures_nested_nodes() {
+    use MyToken::{Comma, One, Zero};
+
+    // `Pair` captures two nested `TwoBit` nodes (each parsed with `TwoBit::parse`) separated
+    // by a literal `Comma` token.
+    let tokens = vec![Zero, Zero, Comma, One, One];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+
+    let pair = Pair::parse(&mut stream).unwrap();
+    assert_eq!(pair, Pair::Of(TwoBit::ZeroZero, TwoBit::OneOne));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Zero,
+    One,
+    Comma,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+impl ParseError<MyToken> for MyError {
+    fn expected(_pos: usize, _what: &str, _found: Option<&MyToken>) -> Self {
+        MyError
+    }
+
+    fn unexpected_eof(_pos: usize, _what: &str) -> Self {
+        MyError
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
+    type Root = TwoBit;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(token_stream.expected_err("a specific token"))
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Ast)]
+#[ast(MyToken, MyError)]
+pub enum TwoBit {
+    #[ast(Zero, Zero)]
+    ZeroZero,
+    #[ast(Zero, One)]
+    ZeroOne,
+    #[ast(One, Zero)]
+    OneZero,
+    #[ast(One, One)]
+    OneOne,
+}
+
+#[derive(Debug, PartialEq, Ast)]
+#[ast(MyToken, MyError)]
+pub enum Pair {
+    #[ast(Left(TwoBit), Comma, Right(TwoBit))]
+    Of(TwoBit, TwoBit),
+}
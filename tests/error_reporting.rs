@@ -0,0 +1,119 @@
+use parsey::{Ast, ParseError, Parser, TokenStream};
+
+#[test]
+pub fn expected_err_reports_position_and_found_token() {
+    let tokens = vec![MyToken::Zero, MyToken::One];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+
+    stream.next();
+    assert_eq!(stream.position(), 1);
+
+    let err = stream.expected_err("a digit group");
+    assert_eq!(
+        err,
+        MyError::Expected {
+            pos: 1,
+            what: "a digit group".to_string(),
+            found: Some("One".to_string()),
+        }
+    );
+}
+
+#[test]
+pub fn expected_err_reports_unexpected_eof() {
+    let tokens: Vec<MyToken> = vec![];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+
+    let err = stream.expected_err("a digit group");
+    assert_eq!(
+        err,
+        MyError::UnexpectedEof {
+            pos: 0,
+            what: "a digit group".to_string(),
+        }
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Zero,
+    One,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MyError {
+    Expected {
+        pos: usize,
+        what: String,
+        found: Option<String>,
+    },
+    UnexpectedEof {
+        pos: usize,
+        what: String,
+    },
+}
+
+impl ParseError<MyToken> for MyError {
+    fn expected(pos: usize, what: &str, found: Option<&MyToken>) -> Self {
+        MyError::Expected {
+            pos,
+            what: what.to_string(),
+            found: found.map(|token| format!("{:?}", token)),
+        }
+    }
+
+    fn unexpected_eof(pos: usize, what: &str) -> Self {
+        MyError::UnexpectedEof {
+            pos,
+            what: what.to_string(),
+        }
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
+    type Root = Root;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(token_stream.expected_err("a specific token"))
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Root;
+
+impl Ast<MyToken, MyError> for Root {
+    fn parse<P>(_token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        Ok(Self)
+    }
+}
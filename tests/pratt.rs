@@ -0,0 +1,139 @@
+use parsey::{Ast, InfixOp, ParseError, Parser, PrattAst, TokenStream};
+
+#[test]
+pub fn parse_expr_honours_precedence() {
+    use MyToken::{Num, Plus, Star};
+
+    // 1 + 2 * 3  ==  1 + (2 * 3)
+    let tokens = vec![Num(1), Plus, Num(2), Star, Num(3)];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+    let expr: Expr = stream.parse_expr(0).unwrap();
+
+    assert_eq!(
+        expr,
+        Expr::Add(
+            Box::new(Expr::Num(1)),
+            Box::new(Expr::Mul(Box::new(Expr::Num(2)), Box::new(Expr::Num(3)))),
+        )
+    );
+}
+
+#[test]
+pub fn parse_expr_is_left_associative_for_equal_precedence() {
+    use MyToken::{Num, Plus};
+
+    // 1 + 2 + 3  ==  (1 + 2) + 3
+    let tokens = vec![Num(1), Plus, Num(2), Plus, Num(3)];
+    let mut stream = TokenStream::from(MyParser::from(tokens));
+    let expr: Expr = stream.parse_expr(0).unwrap();
+
+    assert_eq!(
+        expr,
+        Expr::Add(
+            Box::new(Expr::Add(Box::new(Expr::Num(1)), Box::new(Expr::Num(2)))),
+            Box::new(Expr::Num(3)),
+        )
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MyToken {
+    Num(u8),
+    Plus,
+    Star,
+}
+
+impl InfixOp<MyToken> for MyToken {
+    fn binding_power(tok: &MyToken) -> Option<(u8, u8)> {
+        match tok {
+            MyToken::Plus => Some((1, 2)),
+            MyToken::Star => Some((3, 4)),
+            MyToken::Num(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MyError;
+
+impl ParseError<MyToken> for MyError {
+    fn expected(_pos: usize, _what: &str, _found: Option<&MyToken>) -> Self {
+        MyError
+    }
+
+    fn unexpected_eof(_pos: usize, _what: &str) -> Self {
+        MyError
+    }
+}
+
+pub struct MyParser {
+    tokens: Vec<MyToken>,
+}
+
+impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
+    type Root = Expr;
+
+    fn expect(
+        token_stream: &mut TokenStream<Self, MyToken, MyError>,
+        token: MyToken,
+    ) -> Result<(), MyError> {
+        if token_stream.peek() == Some(&token) {
+            token_stream.next();
+            Ok(())
+        } else {
+            Err(MyError)
+        }
+    }
+}
+
+impl Iterator for MyParser {
+    type Item = MyToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.pop()
+    }
+}
+
+impl From<Vec<MyToken>> for MyParser {
+    fn from(mut value: Vec<MyToken>) -> Self {
+        value.reverse();
+        Self { tokens: value }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Expr {
+    Num(u8),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Ast<MyToken, MyError> for Expr {
+    fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        token_stream.parse_expr(0)
+    }
+}
+
+impl PrattAst<MyToken, MyError> for Expr {
+    fn parse_primary<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
+    where
+        P: Parser<MyToken, MyError, Context = ()>,
+    {
+        match token_stream.next() {
+            Some(MyToken::Num(n)) => Ok(Expr::Num(n)),
+            _ => Err(MyError),
+        }
+    }
+
+    fn build_binop(op: MyToken, lhs: Self, rhs: Self) -> Self {
+        match op {
+            MyToken::Plus => Expr::Add(Box::new(lhs), Box::new(rhs)),
+            MyToken::Star => Expr::Mul(Box::new(lhs), Box::new(rhs)),
+            MyToken::Num(_) => unreachable!("Num is not an operator"),
+        }
+    }
+}
@@ -10,7 +10,7 @@ pub fn two_bit() {
     assert_eq!(ast, Ok(Root(vec![ZeroZero, ZeroOne, OneZero, OneOne])));
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MyToken {
     Zero,
     One,
@@ -24,6 +24,7 @@ pub struct MyParser {
 }
 
 impl Parser<MyToken, MyError> for MyParser {
+    type Context = ();
     type Root = Root;
 
     fn expect(
@@ -68,7 +69,7 @@ pub enum TwoBit {
 impl Ast<MyToken, MyError> for Root {
     fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
     where
-        P: Parser<MyToken, MyError>,
+        P: Parser<MyToken, MyError, Context = ()>,
     {
         let mut two_bits = vec![];
         while !token_stream.is_empty() {
@@ -81,7 +82,7 @@ impl Ast<MyToken, MyError> for Root {
 impl Ast<MyToken, MyError> for TwoBit {
     fn parse<P>(token_stream: &mut TokenStream<P, MyToken, MyError>) -> Result<Self, MyError>
     where
-        P: parsey::Parser<MyToken, MyError>,
+        P: parsey::Parser<MyToken, MyError, Context = ()>,
     {
         use MyToken::*;
         use TwoBit::*;